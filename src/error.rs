@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::peer::ice::ice_candidate::ice_candidate_type::InvalidCandidateType;
+use crate::peer::ice::ice_protocol::InvalidProtocol;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("malformed candidate line: {0}")]
+    ParseCandidate(String),
+
+    #[error("{0}")]
+    InvalidCandidateType(#[from] InvalidCandidateType),
+
+    #[error("{0}")]
+    InvalidProtocol(#[from] InvalidProtocol),
+
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("failed to encode: {0}")]
+    Encode(String),
+
+    #[error("failed to decode: {0}")]
+    Decode(String),
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// ICECandidateType represents the type of the ICE candidate used.
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ICECandidateType {
     Unspecified,
 
@@ -52,13 +52,7 @@ const ICE_CANDIDATE_TYPE_RELAY_STR: &str = "relay";
 ///  takes a string and converts it into ICECandidateType
 impl From<&str> for ICECandidateType {
     fn from(raw: &str) -> Self {
-        match raw {
-            ICE_CANDIDATE_TYPE_HOST_STR => ICECandidateType::Host,
-            ICE_CANDIDATE_TYPE_SRFLX_STR => ICECandidateType::Srflx,
-            ICE_CANDIDATE_TYPE_PRFLX_STR => ICECandidateType::Prflx,
-            ICE_CANDIDATE_TYPE_RELAY_STR => ICECandidateType::Relay,
-            _ => ICECandidateType::Unspecified,
-        }
+        Self::parse(raw).unwrap_or(ICECandidateType::Unspecified)
     }
 }
 
@@ -74,6 +68,61 @@ impl From<CandidateType> for ICECandidateType {
     }
 }
 
+/// Type preferences recommended by RFC 8445 section 5.1.2.1 for computing
+/// candidate priority. Each value lies in 0..=126, and host, peer reflexive,
+/// server reflexive, and relayed preferences are in decreasing order of
+/// preference.
+const TYPE_PREFERENCE_HOST: u16 = 126;
+const TYPE_PREFERENCE_PRFLX: u16 = 110;
+const TYPE_PREFERENCE_SRFLX: u16 = 100;
+const TYPE_PREFERENCE_RELAY: u16 = 0;
+
+impl ICECandidateType {
+    /// type_preference returns the RFC 8445 section 5.1.2.1 type preference
+    /// for this candidate type, used as an input to candidate priority
+    /// computation. `Unspecified` has no defined preference and returns 0,
+    /// the same value as `Relay`.
+    pub fn type_preference(&self) -> u16 {
+        match *self {
+            ICECandidateType::Host => TYPE_PREFERENCE_HOST,
+            ICECandidateType::Prflx => TYPE_PREFERENCE_PRFLX,
+            ICECandidateType::Srflx => TYPE_PREFERENCE_SRFLX,
+            ICECandidateType::Relay => TYPE_PREFERENCE_RELAY,
+            ICECandidateType::Unspecified => 0,
+        }
+    }
+
+    /// parse is the fallible counterpart to `From<&str>`: it rejects any
+    /// token that isn't a recognized RFC 8445 candidate type instead of
+    /// silently collapsing it into `Unspecified`. Callers ingesting remote
+    /// SDP should prefer this over `From` so a malformed `typ` can be
+    /// logged and the candidate dropped, rather than quietly producing an
+    /// `Unspecified` candidate that only fails later, during pairing.
+    pub fn parse(raw: &str) -> std::result::Result<Self, InvalidCandidateType> {
+        match raw {
+            ICE_CANDIDATE_TYPE_HOST_STR => Ok(ICECandidateType::Host),
+            ICE_CANDIDATE_TYPE_SRFLX_STR => Ok(ICECandidateType::Srflx),
+            ICE_CANDIDATE_TYPE_PRFLX_STR => Ok(ICECandidateType::Prflx),
+            ICE_CANDIDATE_TYPE_RELAY_STR => Ok(ICECandidateType::Relay),
+            _ => Err(InvalidCandidateType(raw.to_owned())),
+        }
+    }
+}
+
+/// InvalidCandidateType is returned by `ICECandidateType::parse` when `raw`
+/// does not match any known RFC 8445 `typ` token. It carries the offending
+/// token so callers can include it in their own error/log output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCandidateType(pub String);
+
+impl fmt::Display for InvalidCandidateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid ICE candidate type: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidCandidateType {}
+
 impl fmt::Display for ICECandidateType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -120,4 +169,38 @@ mod test {
             assert_eq!(expected_string, ctype.to_string());
         }
     }
+
+    #[test]
+    fn test_ice_candidate_type_preference() {
+        let tests = vec![
+            (ICECandidateType::Host, 126),
+            (ICECandidateType::Prflx, 110),
+            (ICECandidateType::Srflx, 100),
+            (ICECandidateType::Relay, 0),
+        ];
+
+        for (ctype, expected_preference) in tests {
+            assert_eq!(expected_preference, ctype.type_preference());
+        }
+    }
+
+    #[test]
+    fn test_ice_candidate_type_parse() {
+        let tests = vec![
+            ("host", ICECandidateType::Host),
+            ("srflx", ICECandidateType::Srflx),
+            ("prflx", ICECandidateType::Prflx),
+            ("relay", ICECandidateType::Relay),
+        ];
+
+        for (type_string, expected_type) in tests {
+            assert_eq!(expected_type, ICECandidateType::parse(type_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_ice_candidate_type_parse_invalid() {
+        let err = ICECandidateType::parse("bogus").unwrap_err();
+        assert_eq!(InvalidCandidateType("bogus".to_owned()), err);
+    }
 }
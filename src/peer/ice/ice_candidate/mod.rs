@@ -0,0 +1,324 @@
+pub mod ice_candidate_type;
+
+use ice_candidate_type::ICECandidateType;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::net::{IpAddr, SocketAddr};
+
+use super::ice_protocol::ICEProtocol;
+use crate::error::{Error, Result};
+
+/// LOCAL_PREFERENCE_DEFAULT is the local preference to use when an agent has
+/// only a single interface, as recommended by RFC 8445 section 5.1.2.1. When
+/// an agent is multihomed it SHOULD instead bias this value per interface
+/// (e.g. favoring IPv6 over IPv4, or a preferred network path).
+pub const LOCAL_PREFERENCE_DEFAULT: u16 = 65535;
+
+/// candidate_priority computes an ICE candidate's priority as defined in
+/// RFC 8445 section 5.1.2.1:
+///
+/// ```text
+/// priority = (2^24) * type_preference +
+///            (2^8)  * local_preference +
+///            (2^0)  * (256 - component_id)
+/// ```
+///
+/// `component_id` is 1 for RTP and 2 for RTCP, and per RFC 8445 is always in
+/// `1..=256`; any larger value saturates the `(256 - component_id)` term to
+/// 0 rather than panicking or wrapping. `local_preference` should be
+/// `LOCAL_PREFERENCE_DEFAULT` unless the agent is multihomed.
+pub fn candidate_priority(
+    candidate_type: ICECandidateType,
+    component_id: u16,
+    local_preference: u16,
+) -> u32 {
+    let component_term = 256u32.saturating_sub(u32::from(component_id));
+    (u32::from(candidate_type.type_preference()) << 24)
+        + (u32::from(local_preference) << 8)
+        + component_term
+}
+
+/// FOUNDATION_HASH_SEED is an arbitrary fixed seed mixed into every call to
+/// `compute_foundation`. `DefaultHasher::new()` always starts from the same
+/// keys, so the resulting foundation is stable across separate gathering
+/// passes, which RFC 8445 section 5.1.1.3 requires for correct
+/// frozen-candidate pairing.
+const FOUNDATION_HASH_SEED: u64 = 0xA5A5_F00D_1CE0_0001;
+
+/// compute_foundation derives the RFC 8445 section 5.1.1.3 foundation for a
+/// candidate. Two candidates share a foundation iff they have the same
+/// type, the same base address, were learned from the same STUN/TURN
+/// `server` (for reflexive/relay candidates, `None` otherwise), and use the
+/// same transport `proto`.
+pub fn compute_foundation(
+    candidate_type: ICECandidateType,
+    base: IpAddr,
+    server: Option<SocketAddr>,
+    proto: ICEProtocol,
+) -> String {
+    let mut hasher = DefaultHasher::new();
+    FOUNDATION_HASH_SEED.hash(&mut hasher);
+    candidate_type.hash(&mut hasher);
+    base.hash(&mut hasher);
+    server.hash(&mut hasher);
+    proto.hash(&mut hasher);
+    hasher.finish().to_string()
+}
+
+/// CANDIDATE_PREFIX is the SDP attribute prefix that introduces a
+/// `a=candidate:` line, per https://tools.ietf.org/html/rfc8839#section-5.1.
+const CANDIDATE_PREFIX: &str = "candidate:";
+
+/// ICECandidate is a strongly-typed representation of a single SDP
+/// `a=candidate:` attribute line. It round-trips through `unmarshal_candidate`
+/// / `to_sdp_string`, preserving any trailing extension attributes (such as
+/// `generation` or `network-id`) verbatim and in order. The serialized line
+/// has no leading `a=`, writes the transport in lower case, separates fields
+/// with single spaces, and places `raddr`/`rport` before the extensions.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ICECandidate {
+    pub foundation: String,
+    pub component: u16,
+    pub protocol: ICEProtocol,
+    pub priority: u32,
+    pub address: String,
+    pub port: u16,
+    pub typ: ICECandidateType,
+    pub related_address: Option<String>,
+    pub related_port: Option<u16>,
+    pub extensions: Vec<(String, String)>,
+}
+
+impl fmt::Display for ICECandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_sdp_string())
+    }
+}
+
+impl ICECandidate {
+    /// to_sdp_string serializes this candidate back into an `a=candidate:`
+    /// attribute value, the inverse of `unmarshal_candidate`.
+    pub fn to_sdp_string(&self) -> String {
+        let mut s = format!(
+            "{}{} {} {} {} {} {} typ {}",
+            CANDIDATE_PREFIX,
+            self.foundation,
+            self.component,
+            self.protocol,
+            self.priority,
+            self.address,
+            self.port,
+            self.typ
+        );
+
+        if let Some(raddr) = &self.related_address {
+            s.push_str(&format!(" raddr {}", raddr));
+        }
+        if let Some(rport) = self.related_port {
+            s.push_str(&format!(" rport {}", rport));
+        }
+        for (key, value) in &self.extensions {
+            s.push_str(&format!(" {} {}", key, value));
+        }
+
+        s
+    }
+}
+
+fn next_field<'a>(
+    fields: &mut impl Iterator<Item = &'a str>,
+    name: &str,
+    raw: &str,
+) -> Result<&'a str> {
+    fields
+        .next()
+        .ok_or_else(|| Error::ParseCandidate(format!("missing {} in: {}", name, raw)))
+}
+
+/// unmarshal_candidate parses an SDP `a=candidate:` attribute line (the
+/// leading `a=` is optional) into an `ICECandidate`, per
+/// https://tools.ietf.org/html/rfc8839#section-5.1. Unknown trailing
+/// extension attributes are preserved verbatim so they survive a
+/// parse/`to_sdp_string` round trip.
+pub fn unmarshal_candidate(raw: &str) -> Result<ICECandidate> {
+    let line = raw.strip_prefix("a=").unwrap_or(raw);
+    let line = line.strip_prefix(CANDIDATE_PREFIX).ok_or_else(|| {
+        Error::ParseCandidate(format!("missing `{}` prefix: {}", CANDIDATE_PREFIX, raw))
+    })?;
+
+    let mut fields = line.split_whitespace();
+
+    let foundation = next_field(&mut fields, "foundation", raw)?.to_owned();
+    let component: u16 = next_field(&mut fields, "component", raw)?
+        .parse()
+        .map_err(|_| Error::ParseCandidate(format!("invalid component in: {}", raw)))?;
+    let protocol = ICEProtocol::parse(next_field(&mut fields, "transport", raw)?)?;
+    let priority: u32 = next_field(&mut fields, "priority", raw)?
+        .parse()
+        .map_err(|_| Error::ParseCandidate(format!("invalid priority in: {}", raw)))?;
+    let address = next_field(&mut fields, "address", raw)?.to_owned();
+    let port: u16 = next_field(&mut fields, "port", raw)?
+        .parse()
+        .map_err(|_| Error::ParseCandidate(format!("invalid port in: {}", raw)))?;
+
+    let typ_kw = next_field(&mut fields, "typ", raw)?;
+    if typ_kw != "typ" {
+        return Err(Error::ParseCandidate(format!(
+            "expected `typ`, got `{}` in: {}",
+            typ_kw, raw
+        )));
+    }
+    let typ = ICECandidateType::parse(next_field(&mut fields, "candidate type", raw)?)?;
+
+    let mut related_address = None;
+    let mut related_port = None;
+    let mut extensions = Vec::new();
+
+    while let Some(key) = fields.next() {
+        match key {
+            "raddr" => {
+                related_address = Some(next_field(&mut fields, "raddr", raw)?.to_owned());
+            }
+            "rport" => {
+                related_port = Some(
+                    next_field(&mut fields, "rport", raw)?
+                        .parse()
+                        .map_err(|_| Error::ParseCandidate(format!("invalid rport in: {}", raw)))?,
+                );
+            }
+            _ => {
+                let value = next_field(&mut fields, key, raw)?.to_owned();
+                extensions.push((key.to_owned(), value));
+            }
+        }
+    }
+
+    Ok(ICECandidate {
+        foundation,
+        component,
+        protocol,
+        priority,
+        address,
+        port,
+        typ,
+        related_address,
+        related_port,
+        extensions,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_candidate_priority() {
+        let tests = vec![
+            (ICECandidateType::Host, 1, LOCAL_PREFERENCE_DEFAULT, 2_130_706_431),
+            (ICECandidateType::Srflx, 1, LOCAL_PREFERENCE_DEFAULT, 1_694_498_815),
+            (ICECandidateType::Prflx, 1, LOCAL_PREFERENCE_DEFAULT, 1_862_270_975),
+            (ICECandidateType::Relay, 1, LOCAL_PREFERENCE_DEFAULT, 16_777_215),
+        ];
+
+        for (ctype, component_id, local_preference, expected_priority) in tests {
+            assert_eq!(
+                expected_priority,
+                candidate_priority(ctype, component_id, local_preference)
+            );
+        }
+    }
+
+    #[test]
+    fn test_candidate_priority_component_id() {
+        let rtp = candidate_priority(ICECandidateType::Host, 1, LOCAL_PREFERENCE_DEFAULT);
+        let rtcp = candidate_priority(ICECandidateType::Host, 2, LOCAL_PREFERENCE_DEFAULT);
+        assert!(rtp > rtcp);
+    }
+
+    #[test]
+    fn test_candidate_priority_component_id_out_of_range() {
+        let at_max = candidate_priority(ICECandidateType::Host, 256, LOCAL_PREFERENCE_DEFAULT);
+        let beyond_max = candidate_priority(ICECandidateType::Host, 300, LOCAL_PREFERENCE_DEFAULT);
+        assert_eq!(at_max, beyond_max);
+    }
+
+    #[test]
+    fn test_compute_foundation_stable() {
+        let base: IpAddr = "192.168.1.5".parse().unwrap();
+
+        let a = compute_foundation(ICECandidateType::Host, base, None, ICEProtocol::Udp);
+        let b = compute_foundation(ICECandidateType::Host, base, None, ICEProtocol::Udp);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_compute_foundation_differs() {
+        let base: IpAddr = "192.168.1.5".parse().unwrap();
+        let other_base: IpAddr = "192.168.1.6".parse().unwrap();
+
+        let host = compute_foundation(ICECandidateType::Host, base, None, ICEProtocol::Udp);
+        let relay = compute_foundation(ICECandidateType::Relay, base, None, ICEProtocol::Udp);
+        let tcp = compute_foundation(ICECandidateType::Host, base, None, ICEProtocol::Tcp);
+        let other = compute_foundation(ICECandidateType::Host, other_base, None, ICEProtocol::Udp);
+
+        assert_ne!(host, relay);
+        assert_ne!(host, tcp);
+        assert_ne!(host, other);
+    }
+
+    #[test]
+    fn test_unmarshal_candidate_host() {
+        let raw = "candidate:1 1 udp 2130706431 192.168.1.5 54321 typ host generation 0";
+        let candidate = unmarshal_candidate(raw).unwrap();
+
+        assert_eq!("1", candidate.foundation);
+        assert_eq!(1, candidate.component);
+        assert_eq!(ICEProtocol::Udp, candidate.protocol);
+        assert_eq!(2_130_706_431, candidate.priority);
+        assert_eq!("192.168.1.5", candidate.address);
+        assert_eq!(54321, candidate.port);
+        assert_eq!(ICECandidateType::Host, candidate.typ);
+        assert_eq!(None, candidate.related_address);
+        assert_eq!(None, candidate.related_port);
+        assert_eq!(vec![("generation".to_owned(), "0".to_owned())], candidate.extensions);
+    }
+
+    #[test]
+    fn test_unmarshal_candidate_srflx_with_raddr() {
+        let raw =
+            "candidate:2 1 udp 1694498815 203.0.113.1 54321 typ srflx raddr 192.168.1.5 rport 54321";
+        let candidate = unmarshal_candidate(raw).unwrap();
+
+        assert_eq!(ICECandidateType::Srflx, candidate.typ);
+        assert_eq!(Some("192.168.1.5".to_owned()), candidate.related_address);
+        assert_eq!(Some(54321), candidate.related_port);
+    }
+
+    #[test]
+    fn test_unmarshal_candidate_round_trip() {
+        let raw = "candidate:1 1 udp 2130706431 192.168.1.5 54321 typ host generation 0";
+        let candidate = unmarshal_candidate(raw).unwrap();
+        assert_eq!(raw, candidate.to_sdp_string());
+    }
+
+    #[test]
+    fn test_unmarshal_candidate_missing_prefix() {
+        let err = unmarshal_candidate("1 1 udp 2130706431 192.168.1.5 54321 typ host").unwrap_err();
+        assert!(matches!(err, Error::ParseCandidate(_)));
+    }
+
+    #[test]
+    fn test_unmarshal_candidate_rejects_unknown_type() {
+        let raw = "candidate:1 1 udp 2130706431 192.168.1.5 54321 typ bogus";
+        let err = unmarshal_candidate(raw).unwrap_err();
+        assert!(matches!(err, Error::InvalidCandidateType(_)));
+    }
+
+    #[test]
+    fn test_unmarshal_candidate_rejects_unknown_transport() {
+        let raw = "candidate:1 1 bogus 2130706431 192.168.1.5 54321 typ host";
+        let err = unmarshal_candidate(raw).unwrap_err();
+        assert!(matches!(err, Error::InvalidProtocol(_)));
+    }
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// ICEProtocol indicates the transport protocol type that is used in the
+/// ice.URL structure.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ICEProtocol {
+    Unspecified,
+
+    /// Udp indicates the URL uses a UDP transport.
+    #[serde(rename = "udp")]
+    Udp,
+
+    /// Tcp indicates the URL uses a TCP transport.
+    #[serde(rename = "tcp")]
+    Tcp,
+}
+
+impl Default for ICEProtocol {
+    fn default() -> Self {
+        ICEProtocol::Unspecified
+    }
+}
+
+const ICE_PROTOCOL_UDP_STR: &str = "udp";
+const ICE_PROTOCOL_TCP_STR: &str = "tcp";
+
+/// takes a string and converts it to ICEProtocol
+impl From<&str> for ICEProtocol {
+    fn from(raw: &str) -> Self {
+        Self::parse(raw).unwrap_or(ICEProtocol::Unspecified)
+    }
+}
+
+impl ICEProtocol {
+    /// parse is the fallible counterpart to `From<&str>`: it rejects any
+    /// token that isn't a recognized transport instead of silently
+    /// collapsing it into `Unspecified`. Callers ingesting remote SDP
+    /// should prefer this over `From` so a malformed transport can be
+    /// logged and the candidate dropped, rather than quietly producing an
+    /// `Unspecified` candidate that corrupts any later re-serialization.
+    pub fn parse(raw: &str) -> std::result::Result<Self, InvalidProtocol> {
+        match raw.to_lowercase().as_str() {
+            ICE_PROTOCOL_UDP_STR => Ok(ICEProtocol::Udp),
+            ICE_PROTOCOL_TCP_STR => Ok(ICEProtocol::Tcp),
+            _ => Err(InvalidProtocol(raw.to_owned())),
+        }
+    }
+}
+
+/// InvalidProtocol is returned by `ICEProtocol::parse` when `raw` does not
+/// match any known transport token. It carries the offending token so
+/// callers can include it in their own error/log output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidProtocol(pub String);
+
+impl fmt::Display for InvalidProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid ICE protocol: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidProtocol {}
+
+impl fmt::Display for ICEProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ICEProtocol::Udp => write!(f, "{}", ICE_PROTOCOL_UDP_STR),
+            ICEProtocol::Tcp => write!(f, "{}", ICE_PROTOCOL_TCP_STR),
+            ICEProtocol::Unspecified => write!(f, "{}", crate::UNSPECIFIED_STR),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ice_protocol() {
+        let tests = vec![
+            ("Unspecified", ICEProtocol::Unspecified),
+            ("udp", ICEProtocol::Udp),
+            ("UDP", ICEProtocol::Udp),
+            ("tcp", ICEProtocol::Tcp),
+            ("TCP", ICEProtocol::Tcp),
+        ];
+
+        for (protocol_string, expected_protocol) in tests {
+            let actual = ICEProtocol::from(protocol_string);
+            assert_eq!(expected_protocol, actual);
+        }
+    }
+
+    #[test]
+    fn test_ice_protocol_string() {
+        let tests = vec![
+            (ICEProtocol::Unspecified, "Unspecified"),
+            (ICEProtocol::Udp, "udp"),
+            (ICEProtocol::Tcp, "tcp"),
+        ];
+
+        for (protocol, expected_string) in tests {
+            assert_eq!(expected_string, protocol.to_string());
+        }
+    }
+
+    #[test]
+    fn test_ice_protocol_parse() {
+        let tests = vec![
+            ("udp", ICEProtocol::Udp),
+            ("UDP", ICEProtocol::Udp),
+            ("tcp", ICEProtocol::Tcp),
+            ("TCP", ICEProtocol::Tcp),
+        ];
+
+        for (protocol_string, expected_protocol) in tests {
+            assert_eq!(expected_protocol, ICEProtocol::parse(protocol_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_ice_protocol_parse_invalid() {
+        let err = ICEProtocol::parse("bogus").unwrap_err();
+        assert_eq!(InvalidProtocol("bogus".to_owned()), err);
+    }
+}
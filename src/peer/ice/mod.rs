@@ -7,6 +7,7 @@ pub mod ice_gather;
 pub mod ice_protocol;
 pub mod ice_role;
 pub mod ice_server;
+pub mod signaling_format;
 
 /// ICEParameters includes the ICE username fragment
 /// and password and other ICE-related parameters.
@@ -0,0 +1,105 @@
+use super::ICEParameters;
+use crate::error::{Error, Result};
+
+/// SignalingFormat selects the wire encoding used when exchanging
+/// `ICEParameters` (and, by extension, trickled candidates) over a
+/// signaling channel. `Json` keeps byte-for-byte compatibility with
+/// browsers' SDP/JSON signaling path; `Cbor` and `Bincode` trade that
+/// interop for a much smaller payload on constrained or low-bandwidth
+/// transports and are gated behind their respective cargo features.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SignalingFormat {
+    Json,
+    #[cfg(feature = "cbor")]
+    Cbor,
+    #[cfg(feature = "bincode")]
+    Bincode,
+}
+
+impl Default for SignalingFormat {
+    fn default() -> Self {
+        SignalingFormat::Json
+    }
+}
+
+/// encode_ice_parameters serializes `params` using the wire format selected
+/// by `fmt`.
+pub fn encode_ice_parameters(params: &ICEParameters, fmt: SignalingFormat) -> Result<Vec<u8>> {
+    match fmt {
+        SignalingFormat::Json => serde_json::to_vec(params).map_err(Error::from),
+        #[cfg(feature = "cbor")]
+        SignalingFormat::Cbor => {
+            let mut buf = Vec::new();
+            ciborium::ser::into_writer(params, &mut buf)
+                .map_err(|e| Error::Encode(e.to_string()))?;
+            Ok(buf)
+        }
+        #[cfg(feature = "bincode")]
+        SignalingFormat::Bincode => {
+            bincode::serialize(params).map_err(|e| Error::Encode(e.to_string()))
+        }
+    }
+}
+
+/// decode_ice_parameters is the inverse of `encode_ice_parameters`.
+pub fn decode_ice_parameters(data: &[u8], fmt: SignalingFormat) -> Result<ICEParameters> {
+    match fmt {
+        SignalingFormat::Json => serde_json::from_slice(data).map_err(Error::from),
+        #[cfg(feature = "cbor")]
+        SignalingFormat::Cbor => {
+            ciborium::de::from_reader(data).map_err(|e| Error::Decode(e.to_string()))
+        }
+        #[cfg(feature = "bincode")]
+        SignalingFormat::Bincode => {
+            bincode::deserialize(data).map_err(|e| Error::Decode(e.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_json_round_trip() {
+        let params = ICEParameters {
+            username_fragment: "ufrag".to_owned(),
+            password: "pwd".to_owned(),
+            ice_lite: false,
+        };
+
+        let encoded = encode_ice_parameters(&params, SignalingFormat::Json).unwrap();
+        let decoded = decode_ice_parameters(&encoded, SignalingFormat::Json).unwrap();
+        assert_eq!(params, decoded);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[test]
+    fn test_encode_decode_cbor_round_trip() {
+        let params = ICEParameters {
+            username_fragment: "ufrag".to_owned(),
+            password: "pwd".to_owned(),
+            ice_lite: true,
+        };
+
+        let encoded = encode_ice_parameters(&params, SignalingFormat::Cbor).unwrap();
+        assert!(encoded.len() < encode_ice_parameters(&params, SignalingFormat::Json).unwrap().len());
+
+        let decoded = decode_ice_parameters(&encoded, SignalingFormat::Cbor).unwrap();
+        assert_eq!(params, decoded);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_encode_decode_bincode_round_trip() {
+        let params = ICEParameters {
+            username_fragment: "ufrag".to_owned(),
+            password: "pwd".to_owned(),
+            ice_lite: true,
+        };
+
+        let encoded = encode_ice_parameters(&params, SignalingFormat::Bincode).unwrap();
+        let decoded = decode_ice_parameters(&encoded, SignalingFormat::Bincode).unwrap();
+        assert_eq!(params, decoded);
+    }
+}